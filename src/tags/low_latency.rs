@@ -0,0 +1,679 @@
+use std::error;
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use trackable::error::ErrorKindExt;
+
+use super::parse_yes_or_no;
+use super::version::RequiresVersion;
+use attribute::AttributePairs;
+use types::{ByteRange, DecimalFloatingPoint, ProtocolVersion, QuotedString};
+use {Error, ErrorKind, Result};
+
+/// Converts the `Err` side of a standard-library parse (`ParseIntError`, ...)
+/// into this crate's `Error`, tracking the location at which the conversion
+/// happened.
+fn track_parse<T, E>(result: ::std::result::Result<T, E>) -> Result<T>
+where
+    E: error::Error + Send + Sync + 'static,
+{
+    track!(result.map_err(|e| Error::from(ErrorKind::InvalidInput.cause(e))))
+}
+
+/// [4.4.3.7. EXT-X-PART-INF]
+///
+/// The `ExtXPartInf` tag provides information about the characteristics of
+/// `ExtXPart` tags found in the playlist, and is required if the playlist
+/// contains partial segments.
+///
+/// [4.4.3.7. EXT-X-PART-INF]: https://tools.ietf.org/html/draft-pantos-hls-rfc8216bis-08#section-4.4.3.7
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ExtXPartInf {
+    part_target: DecimalFloatingPoint,
+}
+impl ExtXPartInf {
+    pub(crate) const PREFIX: &'static str = "#EXT-X-PART-INF:";
+
+    /// Makes a new `ExtXPartInf` tag.
+    pub fn new(part_target: DecimalFloatingPoint) -> Self {
+        ExtXPartInf { part_target }
+    }
+
+    /// Returns the maximum duration of any `ExtXPart` tag in the playlist.
+    pub fn part_target(&self) -> DecimalFloatingPoint {
+        self.part_target
+    }
+}
+impl RequiresVersion for ExtXPartInf {
+    /// Returns the protocol compatibility version that this tag requires.
+    fn requires_version(&self) -> ProtocolVersion {
+        ProtocolVersion::V6
+    }
+}
+impl fmt::Display for ExtXPartInf {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Self::PREFIX)?;
+        write!(f, "PART-TARGET={}", self.part_target)?;
+        Ok(())
+    }
+}
+impl FromStr for ExtXPartInf {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        track_assert!(s.starts_with(Self::PREFIX), ErrorKind::InvalidInput);
+
+        let mut part_target = None;
+        let attrs = AttributePairs::parse(s.split_at(Self::PREFIX.len()).1);
+        for attr in attrs {
+            let (key, value) = track!(attr)?;
+            match key {
+                "PART-TARGET" => part_target = Some(track!(value.parse())?),
+                _ => {
+                    // [6.3.1. General Client Responsibilities]
+                    // > ignore any attribute/value pair with an unrecognized AttributeName.
+                }
+            }
+        }
+
+        let part_target = track_assert_some!(part_target, ErrorKind::InvalidInput);
+        Ok(ExtXPartInf { part_target })
+    }
+}
+
+/// [4.4.3.8. EXT-X-SERVER-CONTROL]
+///
+/// The `ExtXServerControl` tag allows the server to indicate support for
+/// delivery features that are not explicit in individual tags elsewhere in
+/// the playlist.
+///
+/// [4.4.3.8. EXT-X-SERVER-CONTROL]: https://tools.ietf.org/html/draft-pantos-hls-rfc8216bis-08#section-4.4.3.8
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ExtXServerControl {
+    can_skip_until: Option<DecimalFloatingPoint>,
+    can_skip_dateranges: bool,
+    part_hold_back: Option<DecimalFloatingPoint>,
+    can_block_reload: bool,
+}
+impl ExtXServerControl {
+    pub(crate) const PREFIX: &'static str = "#EXT-X-SERVER-CONTROL:";
+
+    /// Makes a new `ExtXServerControl` tag.
+    pub fn new() -> Self {
+        ExtXServerControl {
+            can_skip_until: None,
+            can_skip_dateranges: false,
+            part_hold_back: None,
+            can_block_reload: false,
+        }
+    }
+
+    /// Makes a new `ExtXServerControl` tag with the given `CAN-SKIP-UNTIL` value.
+    pub fn with_can_skip_until(mut self, can_skip_until: DecimalFloatingPoint) -> Self {
+        self.can_skip_until = Some(can_skip_until);
+        self
+    }
+
+    /// Makes a new `ExtXServerControl` tag with the given `CAN-SKIP-DATERANGES` flag.
+    pub fn with_can_skip_dateranges(mut self, can_skip_dateranges: bool) -> Self {
+        self.can_skip_dateranges = can_skip_dateranges;
+        self
+    }
+
+    /// Makes a new `ExtXServerControl` tag with the given `PART-HOLD-BACK` value.
+    pub fn with_part_hold_back(mut self, part_hold_back: DecimalFloatingPoint) -> Self {
+        self.part_hold_back = Some(part_hold_back);
+        self
+    }
+
+    /// Makes a new `ExtXServerControl` tag with the given `CAN-BLOCK-RELOAD` flag.
+    pub fn with_can_block_reload(mut self, can_block_reload: bool) -> Self {
+        self.can_block_reload = can_block_reload;
+        self
+    }
+
+    /// Returns the skip boundary for delta playlist updates, if any.
+    pub fn can_skip_until(&self) -> Option<DecimalFloatingPoint> {
+        self.can_skip_until
+    }
+
+    /// Returns whether the server can produce delta playlists that skip `ExtXDateRange` tags.
+    pub fn can_skip_dateranges(&self) -> bool {
+        self.can_skip_dateranges
+    }
+
+    /// Returns the server-recommended hold back for partial segments, if any.
+    pub fn part_hold_back(&self) -> Option<DecimalFloatingPoint> {
+        self.part_hold_back
+    }
+
+    /// Returns whether the server supports blocking playlist reload.
+    pub fn can_block_reload(&self) -> bool {
+        self.can_block_reload
+    }
+}
+impl Default for ExtXServerControl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl RequiresVersion for ExtXServerControl {
+    /// Returns the protocol compatibility version that this tag requires.
+    fn requires_version(&self) -> ProtocolVersion {
+        ProtocolVersion::V6
+    }
+}
+impl fmt::Display for ExtXServerControl {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Self::PREFIX)?;
+
+        let mut written_attr = false;
+        if let Some(value) = self.can_skip_until {
+            write!(f, "CAN-SKIP-UNTIL={}", value)?;
+            written_attr = true;
+        }
+        if self.can_skip_dateranges {
+            if written_attr {
+                write!(f, ",")?;
+            }
+            write!(f, "CAN-SKIP-DATERANGES=YES")?;
+            written_attr = true;
+        }
+        if let Some(value) = self.part_hold_back {
+            if written_attr {
+                write!(f, ",")?;
+            }
+            write!(f, "PART-HOLD-BACK={}", value)?;
+            written_attr = true;
+        }
+        if self.can_block_reload {
+            if written_attr {
+                write!(f, ",")?;
+            }
+            write!(f, "CAN-BLOCK-RELOAD=YES")?;
+        }
+        Ok(())
+    }
+}
+impl FromStr for ExtXServerControl {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        track_assert!(s.starts_with(Self::PREFIX), ErrorKind::InvalidInput);
+
+        let mut server_control = ExtXServerControl::new();
+        let attrs = AttributePairs::parse(s.split_at(Self::PREFIX.len()).1);
+        for attr in attrs {
+            let (key, value) = track!(attr)?;
+            match key {
+                "CAN-SKIP-UNTIL" => server_control.can_skip_until = Some(track!(value.parse())?),
+                "CAN-SKIP-DATERANGES" => {
+                    server_control.can_skip_dateranges = track!(parse_yes_or_no(value))?
+                }
+                "PART-HOLD-BACK" => server_control.part_hold_back = Some(track!(value.parse())?),
+                "CAN-BLOCK-RELOAD" => {
+                    server_control.can_block_reload = track!(parse_yes_or_no(value))?
+                }
+                _ => {
+                    // [6.3.1. General Client Responsibilities]
+                    // > ignore any attribute/value pair with an unrecognized AttributeName.
+                }
+            }
+        }
+
+        Ok(server_control)
+    }
+}
+
+/// [4.4.4.9. EXT-X-PART]
+///
+/// The `ExtXPart` tag identifies a partial segment, which is a sub-range of
+/// a media segment that a server can make available before the full
+/// segment has finished encoding.
+///
+/// [4.4.4.9. EXT-X-PART]: https://tools.ietf.org/html/draft-pantos-hls-rfc8216bis-08#section-4.4.4.9
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ExtXPart {
+    uri: QuotedString,
+    duration: DecimalFloatingPoint,
+    independent: bool,
+    byte_range: Option<ByteRange>,
+    gap: bool,
+}
+impl ExtXPart {
+    pub(crate) const PREFIX: &'static str = "#EXT-X-PART:";
+
+    /// Makes a new `ExtXPart` tag.
+    pub fn new(uri: QuotedString, duration: DecimalFloatingPoint) -> Self {
+        ExtXPart {
+            uri,
+            duration,
+            independent: false,
+            byte_range: None,
+            gap: false,
+        }
+    }
+
+    /// Makes a new `ExtXPart` tag with the given `INDEPENDENT` flag.
+    pub fn with_independent(mut self, independent: bool) -> Self {
+        self.independent = independent;
+        self
+    }
+
+    /// Makes a new `ExtXPart` tag with the given `BYTERANGE`.
+    pub fn with_byte_range(mut self, byte_range: ByteRange) -> Self {
+        self.byte_range = Some(byte_range);
+        self
+    }
+
+    /// Makes a new `ExtXPart` tag with the given `GAP` flag.
+    pub fn with_gap(mut self, gap: bool) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Returns the URI of the partial segment.
+    pub fn uri(&self) -> &QuotedString {
+        &self.uri
+    }
+
+    /// Returns the duration of the partial segment.
+    pub fn duration(&self) -> DecimalFloatingPoint {
+        self.duration
+    }
+
+    /// Returns whether the partial segment is independently decodable.
+    pub fn independent(&self) -> bool {
+        self.independent
+    }
+
+    /// Returns the byte range of the partial segment within its resource, if any.
+    pub fn byte_range(&self) -> Option<ByteRange> {
+        self.byte_range
+    }
+
+    /// Returns whether the partial segment is a gap that should not be loaded.
+    pub fn gap(&self) -> bool {
+        self.gap
+    }
+}
+impl RequiresVersion for ExtXPart {
+    /// Returns the protocol compatibility version that this tag requires.
+    fn requires_version(&self) -> ProtocolVersion {
+        ProtocolVersion::V6
+    }
+}
+impl fmt::Display for ExtXPart {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Self::PREFIX)?;
+        write!(f, "URI={},DURATION={}", self.uri, self.duration)?;
+        if self.independent {
+            write!(f, ",INDEPENDENT=YES")?;
+        }
+        if let Some(value) = self.byte_range {
+            write!(f, ",BYTERANGE=\"{}\"", value)?;
+        }
+        if self.gap {
+            write!(f, ",GAP=YES")?;
+        }
+        Ok(())
+    }
+}
+impl FromStr for ExtXPart {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        track_assert!(s.starts_with(Self::PREFIX), ErrorKind::InvalidInput);
+
+        let mut uri = None;
+        let mut duration = None;
+        let mut independent = false;
+        let mut byte_range = None;
+        let mut gap = false;
+
+        let attrs = AttributePairs::parse(s.split_at(Self::PREFIX.len()).1);
+        for attr in attrs {
+            let (key, value) = track!(attr)?;
+            match key {
+                "URI" => uri = Some(track!(value.parse())?),
+                "DURATION" => duration = Some(track!(value.parse())?),
+                "INDEPENDENT" => independent = track!(parse_yes_or_no(value))?,
+                "BYTERANGE" => {
+                    let quoted: QuotedString = track!(value.parse())?;
+                    byte_range = Some(track!(quoted.as_str().parse())?);
+                }
+                "GAP" => gap = track!(parse_yes_or_no(value))?,
+                _ => {
+                    // [6.3.1. General Client Responsibilities]
+                    // > ignore any attribute/value pair with an unrecognized AttributeName.
+                }
+            }
+        }
+
+        let uri = track_assert_some!(uri, ErrorKind::InvalidInput);
+        let duration = track_assert_some!(duration, ErrorKind::InvalidInput);
+        Ok(ExtXPart {
+            uri,
+            duration,
+            independent,
+            byte_range,
+            gap,
+        })
+    }
+}
+
+/// The type of the resource that an `ExtXPreloadHint` tag hints at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PreloadHintType {
+    /// The hinted resource is a partial segment.
+    Part,
+    /// The hinted resource is a media initialization section.
+    Map,
+}
+impl fmt::Display for PreloadHintType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            PreloadHintType::Part => write!(f, "PART"),
+            PreloadHintType::Map => write!(f, "MAP"),
+        }
+    }
+}
+impl FromStr for PreloadHintType {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "PART" => Ok(PreloadHintType::Part),
+            "MAP" => Ok(PreloadHintType::Map),
+            _ => track_panic!(ErrorKind::InvalidInput),
+        }
+    }
+}
+
+/// [4.4.4.10. EXT-X-PRELOAD-HINT]
+///
+/// The `ExtXPreloadHint` tag allows a server to hint that a client can
+/// start fetching a resource before it is available, as soon as playback
+/// of the playlist has reached its current end.
+///
+/// [4.4.4.10. EXT-X-PRELOAD-HINT]: https://tools.ietf.org/html/draft-pantos-hls-rfc8216bis-08#section-4.4.4.10
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ExtXPreloadHint {
+    hint_type: PreloadHintType,
+    uri: QuotedString,
+    byte_range_start: u64,
+    byte_range_length: Option<u64>,
+}
+impl ExtXPreloadHint {
+    pub(crate) const PREFIX: &'static str = "#EXT-X-PRELOAD-HINT:";
+
+    /// Makes a new `ExtXPreloadHint` tag.
+    pub fn new(hint_type: PreloadHintType, uri: QuotedString) -> Self {
+        ExtXPreloadHint {
+            hint_type,
+            uri,
+            byte_range_start: 0,
+            byte_range_length: None,
+        }
+    }
+
+    /// Makes a new `ExtXPreloadHint` tag hinting at a sub-range of the resource.
+    pub fn with_byte_range(mut self, start: u64, length: u64) -> Self {
+        self.byte_range_start = start;
+        self.byte_range_length = Some(length);
+        self
+    }
+
+    /// Returns the type of the hinted resource.
+    pub fn hint_type(&self) -> PreloadHintType {
+        self.hint_type
+    }
+
+    /// Returns the URI of the hinted resource.
+    pub fn uri(&self) -> &QuotedString {
+        &self.uri
+    }
+
+    /// Returns the offset, in bytes, of the hinted sub-range.
+    pub fn byte_range_start(&self) -> u64 {
+        self.byte_range_start
+    }
+
+    /// Returns the length, in bytes, of the hinted sub-range, if any.
+    pub fn byte_range_length(&self) -> Option<u64> {
+        self.byte_range_length
+    }
+}
+impl RequiresVersion for ExtXPreloadHint {
+    /// Returns the protocol compatibility version that this tag requires.
+    fn requires_version(&self) -> ProtocolVersion {
+        ProtocolVersion::V6
+    }
+}
+impl fmt::Display for ExtXPreloadHint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Self::PREFIX)?;
+        write!(f, "TYPE={},URI={}", self.hint_type, self.uri)?;
+        if self.byte_range_start != 0 {
+            write!(f, ",BYTERANGE-START={}", self.byte_range_start)?;
+        }
+        if let Some(length) = self.byte_range_length {
+            write!(f, ",BYTERANGE-LENGTH={}", length)?;
+        }
+        Ok(())
+    }
+}
+impl FromStr for ExtXPreloadHint {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        track_assert!(s.starts_with(Self::PREFIX), ErrorKind::InvalidInput);
+
+        let mut hint_type = None;
+        let mut uri = None;
+        let mut byte_range_start = 0;
+        let mut byte_range_length = None;
+
+        let attrs = AttributePairs::parse(s.split_at(Self::PREFIX.len()).1);
+        for attr in attrs {
+            let (key, value) = track!(attr)?;
+            match key {
+                "TYPE" => hint_type = Some(track!(value.parse())?),
+                "URI" => uri = Some(track!(value.parse())?),
+                "BYTERANGE-START" => byte_range_start = track_parse(value.parse())?,
+                "BYTERANGE-LENGTH" => byte_range_length = Some(track_parse(value.parse())?),
+                _ => {
+                    // [6.3.1. General Client Responsibilities]
+                    // > ignore any attribute/value pair with an unrecognized AttributeName.
+                }
+            }
+        }
+
+        let hint_type = track_assert_some!(hint_type, ErrorKind::InvalidInput);
+        let uri = track_assert_some!(uri, ErrorKind::InvalidInput);
+        Ok(ExtXPreloadHint {
+            hint_type,
+            uri,
+            byte_range_start,
+            byte_range_length,
+        })
+    }
+}
+
+/// [4.4.4.11. EXT-X-RENDITION-REPORT]
+///
+/// The `ExtXRenditionReport` tag carries information about an associated
+/// media playlist, to be used for Low-Latency HLS delivery.
+///
+/// [4.4.4.11. EXT-X-RENDITION-REPORT]: https://tools.ietf.org/html/draft-pantos-hls-rfc8216bis-08#section-4.4.4.11
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ExtXRenditionReport {
+    uri: QuotedString,
+    last_msn: Option<u64>,
+    last_part: Option<u64>,
+}
+impl ExtXRenditionReport {
+    pub(crate) const PREFIX: &'static str = "#EXT-X-RENDITION-REPORT:";
+
+    /// Makes a new `ExtXRenditionReport` tag.
+    pub fn new(uri: QuotedString) -> Self {
+        ExtXRenditionReport {
+            uri,
+            last_msn: None,
+            last_part: None,
+        }
+    }
+
+    /// Makes a new `ExtXRenditionReport` tag with the given `LAST-MSN`.
+    pub fn with_last_msn(mut self, last_msn: u64) -> Self {
+        self.last_msn = Some(last_msn);
+        self
+    }
+
+    /// Makes a new `ExtXRenditionReport` tag with the given `LAST-PART`.
+    pub fn with_last_part(mut self, last_part: u64) -> Self {
+        self.last_part = Some(last_part);
+        self
+    }
+
+    /// Returns the URI of the associated media playlist.
+    pub fn uri(&self) -> &QuotedString {
+        &self.uri
+    }
+
+    /// Returns the Media Sequence Number of the last low-latency segment
+    /// in the associated media playlist, if known.
+    pub fn last_msn(&self) -> Option<u64> {
+        self.last_msn
+    }
+
+    /// Returns the Part Index of the last partial segment in the associated
+    /// media playlist, if known.
+    pub fn last_part(&self) -> Option<u64> {
+        self.last_part
+    }
+}
+impl RequiresVersion for ExtXRenditionReport {
+    /// Returns the protocol compatibility version that this tag requires.
+    fn requires_version(&self) -> ProtocolVersion {
+        ProtocolVersion::V6
+    }
+}
+impl fmt::Display for ExtXRenditionReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Self::PREFIX)?;
+        write!(f, "URI={}", self.uri)?;
+        if let Some(last_msn) = self.last_msn {
+            write!(f, ",LAST-MSN={}", last_msn)?;
+        }
+        if let Some(last_part) = self.last_part {
+            write!(f, ",LAST-PART={}", last_part)?;
+        }
+        Ok(())
+    }
+}
+impl FromStr for ExtXRenditionReport {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        track_assert!(s.starts_with(Self::PREFIX), ErrorKind::InvalidInput);
+
+        let mut uri = None;
+        let mut last_msn = None;
+        let mut last_part = None;
+
+        let attrs = AttributePairs::parse(s.split_at(Self::PREFIX.len()).1);
+        for attr in attrs {
+            let (key, value) = track!(attr)?;
+            match key {
+                "URI" => uri = Some(track!(value.parse())?),
+                "LAST-MSN" => last_msn = Some(track_parse(value.parse())?),
+                "LAST-PART" => last_part = Some(track_parse(value.parse())?),
+                _ => {
+                    // [6.3.1. General Client Responsibilities]
+                    // > ignore any attribute/value pair with an unrecognized AttributeName.
+                }
+            }
+        }
+
+        let uri = track_assert_some!(uri, ErrorKind::InvalidInput);
+        Ok(ExtXRenditionReport {
+            uri,
+            last_msn,
+            last_part,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn ext_x_part_inf() {
+        let tag = ExtXPartInf::new(DecimalFloatingPoint::new(0.5).unwrap());
+        let text = "#EXT-X-PART-INF:PART-TARGET=0.5";
+        assert_eq!(text.parse().ok(), Some(tag));
+        assert_eq!(tag.to_string(), text);
+        assert_eq!(tag.requires_version(), ProtocolVersion::V6);
+    }
+
+    #[test]
+    fn ext_x_server_control() {
+        let tag = ExtXServerControl::new()
+            .with_can_skip_until(DecimalFloatingPoint::new(12.0).unwrap())
+            .with_part_hold_back(DecimalFloatingPoint::new(1.5).unwrap())
+            .with_can_block_reload(true);
+        let text = "#EXT-X-SERVER-CONTROL:CAN-SKIP-UNTIL=12,PART-HOLD-BACK=1.5,CAN-BLOCK-RELOAD=YES";
+        assert_eq!(text.parse().ok(), Some(tag));
+        assert_eq!(tag.to_string(), text);
+        assert_eq!(tag.requires_version(), ProtocolVersion::V6);
+    }
+
+    #[test]
+    fn ext_x_part() {
+        let tag = ExtXPart::new(
+            QuotedString::new("fileSequence0.ts").unwrap(),
+            DecimalFloatingPoint::new(0.33334).unwrap(),
+        )
+        .with_independent(true)
+        .with_byte_range(ByteRange::new(512, Some(0)))
+        .with_gap(true);
+        let text = "#EXT-X-PART:URI=\"fileSequence0.ts\",DURATION=0.33334,INDEPENDENT=YES,\
+                    BYTERANGE=\"512@0\",GAP=YES";
+        assert_eq!(text.parse().ok(), Some(tag.clone()));
+        assert_eq!(tag.to_string(), text);
+        assert_eq!(tag.requires_version(), ProtocolVersion::V6);
+
+        assert_eq!(tag.uri().as_str(), "fileSequence0.ts");
+        assert!(tag.independent());
+        assert_eq!(tag.byte_range(), Some(ByteRange::new(512, Some(0))));
+        assert!(tag.gap());
+    }
+
+    #[test]
+    fn ext_x_preload_hint() {
+        let tag = ExtXPreloadHint::new(
+            PreloadHintType::Part,
+            QuotedString::new("hint.mp4").unwrap(),
+        );
+        let text = "#EXT-X-PRELOAD-HINT:TYPE=PART,URI=\"hint.mp4\"";
+        assert_eq!(text.parse().ok(), Some(tag.clone()));
+        assert_eq!(tag.to_string(), text);
+        assert_eq!(tag.requires_version(), ProtocolVersion::V6);
+    }
+
+    #[test]
+    fn ext_x_rendition_report() {
+        let tag = ExtXRenditionReport::new(QuotedString::new("low.m3u8").unwrap())
+            .with_last_msn(10)
+            .with_last_part(2);
+        let text = "#EXT-X-RENDITION-REPORT:URI=\"low.m3u8\",LAST-MSN=10,LAST-PART=2";
+        assert_eq!(text.parse().ok(), Some(tag.clone()));
+        assert_eq!(tag.to_string(), text);
+        assert_eq!(tag.requires_version(), ProtocolVersion::V6);
+    }
+}
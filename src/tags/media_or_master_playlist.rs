@@ -1,21 +1,30 @@
 use std::fmt;
 use std::str::FromStr;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 use super::parse_yes_or_no;
+use super::version::RequiresVersion;
 use attribute::AttributePairs;
-use types::{ProtocolVersion, SignedDecimalFloatingPoint};
+use types::{
+    DateTime, DecimalFloatingPoint, HexadecimalSequence, ProtocolVersion, QuotedString,
+    SignedDecimalFloatingPoint,
+};
 use {Error, ErrorKind, Result};
 
 /// [4.3.5.1. EXT-X-INDEPENDENT-SEGMENTS]
 ///
 /// [4.3.5.1. EXT-X-INDEPENDENT-SEGMENTS]: https://tools.ietf.org/html/rfc8216#section-4.3.5.1
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ExtXIndependentSegments;
 impl ExtXIndependentSegments {
     pub(crate) const PREFIX: &'static str = "#EXT-X-INDEPENDENT-SEGMENTS";
-
+}
+impl RequiresVersion for ExtXIndependentSegments {
     /// Returns the protocol compatibility version that this tag requires.
-    pub fn requires_version(self) -> ProtocolVersion {
+    fn requires_version(&self) -> ProtocolVersion {
         ProtocolVersion::V1
     }
 }
@@ -36,6 +45,7 @@ impl FromStr for ExtXIndependentSegments {
 ///
 /// [4.3.5.2. EXT-X-START]: https://tools.ietf.org/html/rfc8216#section-4.3.5.2
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ExtXStart {
     time_offset: SignedDecimalFloatingPoint,
     precise: bool,
@@ -69,9 +79,10 @@ impl ExtXStart {
     pub fn precise(&self) -> bool {
         self.precise
     }
-
+}
+impl RequiresVersion for ExtXStart {
     /// Returns the protocol compatibility version that this tag requires.
-    pub fn requires_version(&self) -> ProtocolVersion {
+    fn requires_version(&self) -> ProtocolVersion {
         ProtocolVersion::V1
     }
 }
@@ -113,6 +124,333 @@ impl FromStr for ExtXStart {
     }
 }
 
+/// An ordered collection of the `X-<client-attribute>` pairs carried by an
+/// `ExtXDateRange` tag.
+///
+/// Unlike the attributes recognized by the other tags in this module,
+/// client attributes are opaque to this crate: their names and values are
+/// defined by whatever application produced the playlist, so they are kept
+/// verbatim (including their original order) rather than being parsed into
+/// a fixed set of fields. `value` is the raw `AttributeValue` text exactly
+/// as it appears after the `=`, quotes included if it was a quoted-string —
+/// this crate has no way to know whether a given `X-` attribute is meant to
+/// be a quoted-string, a hexadecimal-sequence, or a decimal-floating-point,
+/// so re-quoting or unquoting it on parse would silently change its type.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ClientAttributes(Vec<(String, String)>);
+impl ClientAttributes {
+    /// Makes an empty `ClientAttributes`.
+    pub fn new() -> Self {
+        ClientAttributes(Vec::new())
+    }
+
+    /// Inserts a `X-<name>` attribute, overwriting any previous value for the same name.
+    ///
+    /// `name` must not include the `X-` prefix; it is added automatically.
+    /// `value` is stored exactly as given, see the type-level documentation.
+    pub fn insert(&mut self, name: &str, value: &str) {
+        if let Some(entry) = self.0.iter_mut().find(|(k, _)| k == name) {
+            entry.1 = value.to_owned();
+        } else {
+            self.0.push((name.to_owned(), value.to_owned()));
+        }
+    }
+
+    /// Returns the value of the `X-<name>` attribute, if present.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(k, _)| k == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Returns an iterator over the `(name, value)` pairs, in the order they were first inserted.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    /// Returns `true` if there are no client attributes.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// [4.3.2.7. EXT-X-DATERANGE]
+///
+/// The `ExtXDateRange` tag associates a date range (i.e., a range of time
+/// defined by a starting and ending date) with a set of attributes and
+/// their associated value semantics.
+///
+/// [4.3.2.7. EXT-X-DATERANGE]: https://tools.ietf.org/html/rfc8216#section-4.3.2.7
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ExtXDateRange {
+    id: QuotedString,
+    class: Option<QuotedString>,
+    start_date: DateTime,
+    end_date: Option<DateTime>,
+    duration: Option<DecimalFloatingPoint>,
+    planned_duration: Option<DecimalFloatingPoint>,
+    scte35_cmd: Option<HexadecimalSequence>,
+    scte35_out: Option<HexadecimalSequence>,
+    scte35_in: Option<HexadecimalSequence>,
+    end_on_next: bool,
+    client_attributes: ClientAttributes,
+}
+impl ExtXDateRange {
+    pub(crate) const PREFIX: &'static str = "#EXT-X-DATERANGE:";
+
+    /// Makes a new `ExtXDateRange` tag.
+    pub fn new(id: QuotedString, start_date: DateTime) -> Self {
+        ExtXDateRange {
+            id,
+            class: None,
+            start_date,
+            end_date: None,
+            duration: None,
+            planned_duration: None,
+            scte35_cmd: None,
+            scte35_out: None,
+            scte35_in: None,
+            end_on_next: false,
+            client_attributes: ClientAttributes::new(),
+        }
+    }
+
+    /// Makes a new `ExtXDateRange` tag with the given `CLASS`.
+    pub fn with_class(mut self, class: QuotedString) -> Self {
+        self.class = Some(class);
+        self
+    }
+
+    /// Makes a new `ExtXDateRange` tag with the given `END-DATE`.
+    pub fn with_end_date(mut self, end_date: DateTime) -> Self {
+        self.end_date = Some(end_date);
+        self
+    }
+
+    /// Makes a new `ExtXDateRange` tag with the given `DURATION`.
+    pub fn with_duration(mut self, duration: DecimalFloatingPoint) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Makes a new `ExtXDateRange` tag with the given `PLANNED-DURATION`.
+    pub fn with_planned_duration(mut self, planned_duration: DecimalFloatingPoint) -> Self {
+        self.planned_duration = Some(planned_duration);
+        self
+    }
+
+    /// Makes a new `ExtXDateRange` tag with the given `SCTE35-CMD`.
+    pub fn with_scte35_cmd(mut self, scte35_cmd: HexadecimalSequence) -> Self {
+        self.scte35_cmd = Some(scte35_cmd);
+        self
+    }
+
+    /// Makes a new `ExtXDateRange` tag with the given `SCTE35-OUT`.
+    pub fn with_scte35_out(mut self, scte35_out: HexadecimalSequence) -> Self {
+        self.scte35_out = Some(scte35_out);
+        self
+    }
+
+    /// Makes a new `ExtXDateRange` tag with the given `SCTE35-IN`.
+    pub fn with_scte35_in(mut self, scte35_in: HexadecimalSequence) -> Self {
+        self.scte35_in = Some(scte35_in);
+        self
+    }
+
+    /// Makes a new `ExtXDateRange` tag with the given `END-ON-NEXT` flag.
+    pub fn with_end_on_next(mut self, end_on_next: bool) -> Self {
+        self.end_on_next = end_on_next;
+        self
+    }
+
+    /// Makes a new `ExtXDateRange` tag with the given client attributes.
+    pub fn with_client_attributes(mut self, client_attributes: ClientAttributes) -> Self {
+        self.client_attributes = client_attributes;
+        self
+    }
+
+    /// Returns the identifier of the date range.
+    pub fn id(&self) -> &QuotedString {
+        &self.id
+    }
+
+    /// Returns the client-defined class of the date range, if any.
+    pub fn class(&self) -> Option<&QuotedString> {
+        self.class.as_ref()
+    }
+
+    /// Returns the date at which the date range begins.
+    pub fn start_date(&self) -> &DateTime {
+        &self.start_date
+    }
+
+    /// Returns the date at which the date range ends, if any.
+    pub fn end_date(&self) -> Option<&DateTime> {
+        self.end_date.as_ref()
+    }
+
+    /// Returns the duration of the date range, if any.
+    pub fn duration(&self) -> Option<DecimalFloatingPoint> {
+        self.duration
+    }
+
+    /// Returns the expected duration of the date range, if any.
+    pub fn planned_duration(&self) -> Option<DecimalFloatingPoint> {
+        self.planned_duration
+    }
+
+    /// Returns the `SCTE35-CMD` value, if any.
+    pub fn scte35_cmd(&self) -> Option<&HexadecimalSequence> {
+        self.scte35_cmd.as_ref()
+    }
+
+    /// Returns the `SCTE35-OUT` value, if any.
+    pub fn scte35_out(&self) -> Option<&HexadecimalSequence> {
+        self.scte35_out.as_ref()
+    }
+
+    /// Returns the `SCTE35-IN` value, if any.
+    pub fn scte35_in(&self) -> Option<&HexadecimalSequence> {
+        self.scte35_in.as_ref()
+    }
+
+    /// Returns whether this date range ends where the following date range begins.
+    pub fn end_on_next(&self) -> bool {
+        self.end_on_next
+    }
+
+    /// Returns the `X-<client-attribute>` pairs carried by this tag.
+    pub fn client_attributes(&self) -> &ClientAttributes {
+        &self.client_attributes
+    }
+
+    /// Validates the invariants of [4.3.2.7. EXT-X-DATERANGE]:
+    /// an `END-ON-NEXT` date range must have a `CLASS`, and must not carry
+    /// a `DURATION` or `END-DATE`.
+    ///
+    /// [4.3.2.7. EXT-X-DATERANGE]: https://tools.ietf.org/html/rfc8216#section-4.3.2.7
+    pub fn validate(&self) -> Result<()> {
+        if self.end_on_next {
+            track_assert!(self.class.is_some(), ErrorKind::InvalidInput);
+            track_assert!(self.duration.is_none(), ErrorKind::InvalidInput);
+            track_assert!(self.end_date.is_none(), ErrorKind::InvalidInput);
+        }
+        Ok(())
+    }
+}
+impl RequiresVersion for ExtXDateRange {
+    /// Returns the protocol compatibility version that this tag requires.
+    fn requires_version(&self) -> ProtocolVersion {
+        ProtocolVersion::V1
+    }
+}
+impl fmt::Display for ExtXDateRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Self::PREFIX)?;
+        write!(f, "ID={}", self.id)?;
+        if let Some(ref class) = self.class {
+            write!(f, ",CLASS={}", class)?;
+        }
+        write!(f, ",START-DATE=\"{}\"", self.start_date)?;
+        if let Some(ref end_date) = self.end_date {
+            write!(f, ",END-DATE=\"{}\"", end_date)?;
+        }
+        if let Some(duration) = self.duration {
+            write!(f, ",DURATION={}", duration)?;
+        }
+        if let Some(planned_duration) = self.planned_duration {
+            write!(f, ",PLANNED-DURATION={}", planned_duration)?;
+        }
+        for (name, value) in self.client_attributes.iter() {
+            write!(f, ",X-{}={}", name, value)?;
+        }
+        if let Some(ref scte35_cmd) = self.scte35_cmd {
+            write!(f, ",SCTE35-CMD={}", scte35_cmd)?;
+        }
+        if let Some(ref scte35_out) = self.scte35_out {
+            write!(f, ",SCTE35-OUT={}", scte35_out)?;
+        }
+        if let Some(ref scte35_in) = self.scte35_in {
+            write!(f, ",SCTE35-IN={}", scte35_in)?;
+        }
+        if self.end_on_next {
+            write!(f, ",END-ON-NEXT=YES")?;
+        }
+        Ok(())
+    }
+}
+impl FromStr for ExtXDateRange {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        track_assert!(s.starts_with(Self::PREFIX), ErrorKind::InvalidInput);
+
+        let mut id = None;
+        let mut class = None;
+        let mut start_date = None;
+        let mut end_date = None;
+        let mut duration = None;
+        let mut planned_duration = None;
+        let mut scte35_cmd = None;
+        let mut scte35_out = None;
+        let mut scte35_in = None;
+        let mut end_on_next = false;
+        let mut client_attributes = ClientAttributes::new();
+
+        let attrs = AttributePairs::parse(s.split_at(Self::PREFIX.len()).1);
+        for attr in attrs {
+            let (key, value) = track!(attr)?;
+            match key {
+                "ID" => id = Some(track!(value.parse())?),
+                "CLASS" => class = Some(track!(value.parse())?),
+                "START-DATE" => {
+                    let quoted: QuotedString = track!(value.parse())?;
+                    start_date = Some(track!(quoted.as_str().parse())?);
+                }
+                "END-DATE" => {
+                    let quoted: QuotedString = track!(value.parse())?;
+                    end_date = Some(track!(quoted.as_str().parse())?);
+                }
+                "DURATION" => duration = Some(track!(value.parse())?),
+                "PLANNED-DURATION" => planned_duration = Some(track!(value.parse())?),
+                "SCTE35-CMD" => scte35_cmd = Some(track!(value.parse())?),
+                "SCTE35-OUT" => scte35_out = Some(track!(value.parse())?),
+                "SCTE35-IN" => scte35_in = Some(track!(value.parse())?),
+                "END-ON-NEXT" => end_on_next = track!(parse_yes_or_no(value))?,
+                _ => {
+                    if let Some(name) = key.strip_prefix("X-") {
+                        // Client attributes are kept verbatim (see `ClientAttributes`):
+                        // we don't know their type, so we must not unquote or re-quote them.
+                        client_attributes.insert(name, value);
+                    }
+                    // Any other unrecognized attribute/value pair is ignored, as per
+                    // [6.3.1. General Client Responsibilities].
+                }
+            }
+        }
+
+        let id = track_assert_some!(id, ErrorKind::InvalidInput);
+        let start_date = track_assert_some!(start_date, ErrorKind::InvalidInput);
+        let date_range = ExtXDateRange {
+            id,
+            class,
+            start_date,
+            end_date,
+            duration,
+            planned_duration,
+            scte35_cmd,
+            scte35_out,
+            scte35_in,
+            end_on_next,
+            client_attributes,
+        };
+        track!(date_range.validate())?;
+        Ok(date_range)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -140,4 +478,48 @@ mod test {
         assert_eq!(tag.to_string(), text);
         assert_eq!(tag.requires_version(), ProtocolVersion::V1);
     }
+
+    #[test]
+    fn ext_x_daterange() {
+        let tag = ExtXDateRange::new(
+            QuotedString::new("splice-6FFFFFF0").unwrap(),
+            "2014-03-05T11:15:00Z".parse().unwrap(),
+        )
+        .with_planned_duration(DecimalFloatingPoint::new(59.993).unwrap())
+        .with_scte35_out(HexadecimalSequence::new(vec![0xfc, 0x30]));
+        let text = "#EXT-X-DATERANGE:ID=\"splice-6FFFFFF0\",START-DATE=\"2014-03-05T11:15:00Z\",\
+                    PLANNED-DURATION=59.993,SCTE35-OUT=0xFC30";
+        assert_eq!(text.parse().ok(), Some(tag.clone()));
+        assert_eq!(tag.to_string(), text);
+        assert_eq!(tag.requires_version(), ProtocolVersion::V1);
+
+        // A non-string client attribute round-trips without being coerced into a
+        // quoted-string, and without being dropped.
+        let mut attrs = ClientAttributes::new();
+        attrs.insert("COM-EXAMPLE-AD-ID", "\"xyz\"");
+        attrs.insert("COM-EXAMPLE-AD-COUNT", "42");
+        let tag = ExtXDateRange::new(
+            QuotedString::new("test-id").unwrap(),
+            "2014-03-05T11:15:00Z".parse().unwrap(),
+        )
+        .with_client_attributes(attrs);
+        assert_eq!(
+            tag.client_attributes().get("COM-EXAMPLE-AD-ID"),
+            Some("\"xyz\"")
+        );
+        assert_eq!(
+            tag.client_attributes().get("COM-EXAMPLE-AD-COUNT"),
+            Some("42")
+        );
+        let parsed: ExtXDateRange = tag.to_string().parse().unwrap();
+        assert_eq!(parsed, tag);
+
+        // `END-ON-NEXT` requires a `CLASS` and forbids `DURATION`/`END-DATE`.
+        let invalid = ExtXDateRange::new(
+            QuotedString::new("test-id").unwrap(),
+            "2014-03-05T11:15:00Z".parse().unwrap(),
+        )
+        .with_end_on_next(true);
+        assert!(invalid.validate().is_err());
+    }
 }
@@ -0,0 +1,85 @@
+use types::ProtocolVersion;
+use {ErrorKind, Result};
+
+/// A tag that can report the protocol compatibility version it requires,
+/// such as `ExtXStart` or `ExtXPartInf`.
+///
+/// This lets [`required_version`] fold over a playlist's tags directly,
+/// without callers having to pre-map each one to a `ProtocolVersion`
+/// themselves.
+pub trait RequiresVersion {
+    /// Returns the protocol compatibility version that this tag requires.
+    fn requires_version(&self) -> ProtocolVersion;
+}
+
+/// Computes the `EXT-X-VERSION` that a playlist must declare in order to be
+/// compatible with every tag it contains.
+///
+/// This folds `requires_version()` over `tags`, returning the maximum of the
+/// individual requirements, or `ProtocolVersion::V1` if `tags` is empty. See
+/// `required_version_takes_the_maximum` below for a worked example.
+///
+/// Once a playlist type holds its tags as `&dyn RequiresVersion` (or can be
+/// made to produce such an iterator), it should call this in its `Display`
+/// implementation to emit the correct `EXT-X-VERSION` line, and use
+/// [`validate_version`] to reject an explicitly-set version that is too low.
+/// No playlist type exists yet in this crate to wire that up against, so
+/// `required_version`/`validate_version` remain standalone building blocks
+/// until one lands.
+pub fn required_version<'a, I>(tags: I) -> ProtocolVersion
+where
+    I: IntoIterator<Item = &'a dyn RequiresVersion>,
+{
+    tags.into_iter()
+        .map(RequiresVersion::requires_version)
+        .max()
+        .unwrap_or(ProtocolVersion::V1)
+}
+
+/// Checks that `declared`, the `EXT-X-VERSION` a playlist is about to be
+/// serialized with, is high enough to satisfy `required`, the version
+/// returned by [`required_version`].
+///
+/// Returns `ErrorKind::InvalidInput` if `declared` is lower than `required`,
+/// so that a playlist can never be generated with a version that its own
+/// tags are incompatible with.
+pub fn validate_version(declared: ProtocolVersion, required: ProtocolVersion) -> Result<()> {
+    track_assert!(declared >= required, ErrorKind::InvalidInput);
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use super::super::low_latency::ExtXPartInf;
+    use super::super::media_or_master_playlist::{ExtXIndependentSegments, ExtXStart};
+    use types::{DecimalFloatingPoint, SignedDecimalFloatingPoint};
+
+    #[test]
+    fn required_version_of_empty_is_v1() {
+        let tags: Vec<&dyn RequiresVersion> = Vec::new();
+        assert_eq!(required_version(tags), ProtocolVersion::V1);
+    }
+
+    #[test]
+    fn required_version_takes_the_maximum() {
+        let start = ExtXStart::new(SignedDecimalFloatingPoint::new(0.0).unwrap());
+        let part_inf = ExtXPartInf::new(DecimalFloatingPoint::new(0.5).unwrap());
+        let tags: Vec<&dyn RequiresVersion> = vec![&start, &part_inf];
+        assert_eq!(required_version(tags), ProtocolVersion::V6);
+    }
+
+    #[test]
+    fn required_version_of_only_v1_tags_is_v1() {
+        let start = ExtXStart::new(SignedDecimalFloatingPoint::new(0.0).unwrap());
+        let segments = ExtXIndependentSegments;
+        let tags: Vec<&dyn RequiresVersion> = vec![&start, &segments];
+        assert_eq!(required_version(tags), ProtocolVersion::V1);
+    }
+
+    #[test]
+    fn validate_version_rejects_too_low_a_declaration() {
+        assert!(validate_version(ProtocolVersion::V1, ProtocolVersion::V6).is_err());
+        assert!(validate_version(ProtocolVersion::V6, ProtocolVersion::V6).is_ok());
+    }
+}
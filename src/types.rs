@@ -0,0 +1,250 @@
+use std::error;
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::de::{self, Deserializer};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize, Serializer};
+
+use trackable::error::ErrorKindExt;
+
+use {Error, ErrorKind, Result};
+
+/// Converts the `Err` side of a standard-library parse (`ParseIntError`, ...)
+/// into this crate's `Error`, tracking the location at which the conversion
+/// happened. Used only by [`DateTime::from_str`]'s own field parsing.
+fn parse_field<T, E>(result: ::std::result::Result<T, E>) -> Result<T>
+where
+    E: error::Error + Send + Sync + 'static,
+{
+    track!(result.map_err(|e| Error::from(ErrorKind::InvalidInput.cause(e))))
+}
+
+/// Returns the number of days in `month` (1-based) of `year`, accounting for
+/// leap years.
+#[allow(clippy::manual_is_multiple_of)] // `Integer::is_multiple_of` postdates this crate's MSRV
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 => {
+            let is_leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+            if is_leap {
+                29
+            } else {
+                28
+            }
+        }
+        _ => 0,
+    }
+}
+
+/// Where a [`DateTime`] stands relative to UTC.
+///
+/// This is kept distinct from a plain offset so that the `Z` shorthand for
+/// UTC (as opposed to the equivalent but distinct `+00:00`) round-trips
+/// through `FromStr`/`Display` unchanged.
+///
+/// [`DateTime`]: struct.DateTime.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TimeZone {
+    /// The `Z` (Zulu / UTC) designator.
+    Utc,
+    /// A `+HH:MM` or `-HH:MM` offset from UTC, in minutes.
+    Offset(i32),
+}
+impl fmt::Display for TimeZone {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TimeZone::Utc => write!(f, "Z"),
+            TimeZone::Offset(minutes) => {
+                let sign = if minutes < 0 { '-' } else { '+' };
+                let minutes = minutes.abs();
+                write!(f, "{}{:02}:{:02}", sign, minutes / 60, minutes % 60)
+            }
+        }
+    }
+}
+
+/// A date and time, as used by attributes like `START-DATE` and the
+/// `EXT-X-PROGRAM-DATE-TIME` tag, in the subset of ISO/IEC 8601:2004 syntax
+/// required by [4.3.2.7. EXT-X-DATERANGE] (`YYYY-MM-DDTHH:MM:SS(.sss+)?(Z|±HH:MM)`).
+///
+/// The fractional-second digits, if any, are kept verbatim (rather than
+/// normalized to a fixed number of milliseconds) so that a value round-trips
+/// through `Display`/`FromStr` byte-for-byte.
+///
+/// [4.3.2.7. EXT-X-DATERANGE]: https://tools.ietf.org/html/rfc8216#section-4.3.2.7
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DateTime {
+    year: u16,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    fraction: Option<String>,
+    time_zone: TimeZone,
+}
+impl fmt::Display for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )?;
+        if let Some(ref fraction) = self.fraction {
+            write!(f, ".{}", fraction)?;
+        }
+        write!(f, "{}", self.time_zone)
+    }
+}
+impl FromStr for DateTime {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self> {
+        track_assert!(s.len() >= 19, ErrorKind::InvalidInput);
+        track_assert_eq!(s.as_bytes()[4], b'-', ErrorKind::InvalidInput);
+        track_assert_eq!(s.as_bytes()[7], b'-', ErrorKind::InvalidInput);
+        track_assert_eq!(s.as_bytes()[10], b'T', ErrorKind::InvalidInput);
+        track_assert_eq!(s.as_bytes()[13], b':', ErrorKind::InvalidInput);
+        track_assert_eq!(s.as_bytes()[16], b':', ErrorKind::InvalidInput);
+
+        let year = parse_field(s[0..4].parse())?;
+        let month: u8 = parse_field(s[5..7].parse())?;
+        let day: u8 = parse_field(s[8..10].parse())?;
+        let hour: u8 = parse_field(s[11..13].parse())?;
+        let minute: u8 = parse_field(s[14..16].parse())?;
+        let second: u8 = parse_field(s[17..19].parse())?;
+        track_assert!((1..=12).contains(&month), ErrorKind::InvalidInput);
+        track_assert!((1..=days_in_month(year, month)).contains(&day), ErrorKind::InvalidInput);
+        track_assert!(hour <= 23, ErrorKind::InvalidInput);
+        track_assert!(minute <= 59, ErrorKind::InvalidInput);
+        track_assert!(second <= 59, ErrorKind::InvalidInput);
+
+        let mut rest = &s[19..];
+        let mut fraction = None;
+        if rest.starts_with('.') {
+            let end = track_assert_some!(rest.find(['Z', 'z', '+', '-']), ErrorKind::InvalidInput);
+            let digits = &rest[1..end];
+            track_assert!(!digits.is_empty(), ErrorKind::InvalidInput);
+            track_assert!(digits.bytes().all(|b| b.is_ascii_digit()), ErrorKind::InvalidInput);
+            fraction = Some(digits.to_owned());
+            rest = &rest[end..];
+        }
+
+        let time_zone = if rest.eq_ignore_ascii_case("z") {
+            TimeZone::Utc
+        } else {
+            track_assert_eq!(rest.len(), 6, ErrorKind::InvalidInput);
+            track_assert_eq!(rest.as_bytes()[3], b':', ErrorKind::InvalidInput);
+            let sign: i32 = match rest.as_bytes()[0] {
+                b'+' => 1,
+                b'-' => -1,
+                _ => track_panic!(ErrorKind::InvalidInput),
+            };
+            let hours: i32 = parse_field(rest[1..3].parse())?;
+            let minutes: i32 = parse_field(rest[4..6].parse())?;
+            track_assert!(hours <= 23, ErrorKind::InvalidInput);
+            track_assert!(minutes <= 59, ErrorKind::InvalidInput);
+            TimeZone::Offset(sign * (hours * 60 + minutes))
+        };
+
+        Ok(DateTime {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            fraction,
+            time_zone,
+        })
+    }
+}
+
+// Serialized as its M3U8 text form (via `Display`/`FromStr`) rather than
+// deriving on the fields directly, so that deserialization can never
+// bypass the field-range validation done in `FromStr`.
+#[cfg(feature = "serde")]
+impl Serialize for DateTime {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for DateTime {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn date_time_preserves_zulu() {
+        let value: DateTime = "2014-03-05T11:15:00Z".parse().unwrap();
+        assert_eq!(value.to_string(), "2014-03-05T11:15:00Z");
+    }
+
+    #[test]
+    fn date_time_preserves_numeric_offset() {
+        let value: DateTime = "2014-03-05T11:15:00+08:00".parse().unwrap();
+        assert_eq!(value.to_string(), "2014-03-05T11:15:00+08:00");
+
+        let value: DateTime = "2014-03-05T11:15:00.250-05:00".parse().unwrap();
+        assert_eq!(value.to_string(), "2014-03-05T11:15:00.250-05:00");
+    }
+
+    #[test]
+    fn date_time_preserves_fraction_verbatim() {
+        let value: DateTime = "2014-03-05T11:15:00.25Z".parse().unwrap();
+        assert_eq!(value.to_string(), "2014-03-05T11:15:00.25Z");
+
+        let value: DateTime = "2014-03-05T11:15:00.123456Z".parse().unwrap();
+        assert_eq!(value.to_string(), "2014-03-05T11:15:00.123456Z");
+    }
+
+    #[test]
+    fn date_time_rejects_out_of_range_fields() {
+        assert!("2014-13-05T11:15:00Z".parse::<DateTime>().is_err());
+        assert!("2014-03-05T11:15:61Z".parse::<DateTime>().is_err());
+        assert!("2014-03-05T11:15:00+24:00".parse::<DateTime>().is_err());
+    }
+
+    #[test]
+    fn date_time_rejects_invalid_calendar_day() {
+        assert!("2014-02-30T11:15:00Z".parse::<DateTime>().is_err());
+        assert!("2014-04-31T00:00:00Z".parse::<DateTime>().is_err());
+        assert!("2013-02-29T00:00:00Z".parse::<DateTime>().is_err());
+        assert!("2016-02-29T00:00:00Z".parse::<DateTime>().is_ok());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let date: DateTime = "2014-03-05T11:15:00.25Z".parse().unwrap();
+        let json = serde_json::to_string(&date).unwrap();
+        assert_eq!(json, "\"2014-03-05T11:15:00.25Z\"");
+        assert_eq!(serde_json::from_str::<DateTime>(&json).unwrap(), date);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_rejects_invalid_date() {
+        // Deserialize must route through `FromStr`'s validation rather than
+        // bypassing it, so an out-of-range month is rejected, not silently
+        // accepted.
+        assert!(serde_json::from_str::<DateTime>("\"2014-13-05T11:15:00Z\"").is_err());
+    }
+}